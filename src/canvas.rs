@@ -0,0 +1,179 @@
+use crate::primitives::Tuple;
+
+/// The maximum line length allowed by the PPM (P3) format
+const PPM_MAX_LINE_LEN: usize = 70;
+
+/// A grid of color `Tuple`s that can be exported to the PPM image format
+pub struct Canvas {
+    /// The number of pixels per row
+    width: usize,
+    /// The number of pixels per column
+    height: usize,
+    /// The pixels, stored row by row, initialized to black
+    pixels: Vec<Tuple>,
+}
+
+impl Canvas {
+    /// Returns a canvas of the given dimensions with every pixel set to black
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of pixels per row
+    /// * `height` - The number of pixels per column
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            pixels: vec![Tuple::new_color(0.0, 0.0, 0.0); width * height],
+        }
+    }
+
+    /// Sets the color of the pixel at `(x, y)`
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the pixel
+    /// * `y` - The row of the pixel
+    /// * `color` - The color to write
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Tuple) {
+        let index = self.index(x, y);
+        self.pixels[index] = color;
+    }
+
+    /// Returns the color of the pixel at `(x, y)`
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the pixel
+    /// * `y` - The row of the pixel
+    pub fn pixel_at(&self, x: usize, y: usize) -> &Tuple {
+        &self.pixels[self.index(x, y)]
+    }
+
+    /// Returns the index into `pixels` for the given coordinates
+    fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    /// Returns the canvas rendered as a plain PPM (P3) image
+    ///
+    /// # Return
+    ///
+    /// * `String` - The PPM content, with pixel rows wrapped so no line
+    /// exceeds 70 characters, ending with a trailing newline
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = format!("P3\n{} {}\n255\n", self.width, self.height);
+
+        for y in 0..self.height {
+            let mut line = String::new();
+
+            for x in 0..self.width {
+                let color = self.pixel_at(x, y);
+
+                for channel in [color.red(), color.green(), color.blue()] {
+                    let value = scale_channel(channel).to_string();
+
+                    if line.is_empty() {
+                        line.push_str(&value);
+                    } else if line.len() + 1 + value.len() > PPM_MAX_LINE_LEN {
+                        ppm.push_str(&line);
+                        ppm.push('\n');
+                        line = value;
+                    } else {
+                        line.push(' ');
+                        line.push_str(&value);
+                    }
+                }
+            }
+
+            ppm.push_str(&line);
+            ppm.push('\n');
+        }
+
+        ppm
+    }
+}
+
+/// Scales a color channel from `0.0..=1.0` to `0..=255`, clamping and rounding
+fn scale_channel(value: f64) -> i64 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::Canvas;
+    use crate::primitives::Tuple;
+
+    #[test]
+    fn new() {
+        let c = Canvas::new(10, 20);
+
+        for y in 0..20 {
+            for x in 0..10 {
+                assert!(Tuple::new_color(0.0, 0.0, 0.0).is_equal(c.pixel_at(x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn write_pixel() {
+        let mut c = Canvas::new(10, 20);
+        let red = Tuple::new_color(1.0, 0.0, 0.0);
+
+        c.write_pixel(2, 3, red.clone());
+
+        assert!(red.is_equal(c.pixel_at(2, 3)));
+    }
+
+    #[test]
+    fn to_ppm_header() {
+        let c = Canvas::new(5, 3);
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().collect();
+
+        assert_eq!(lines[0], "P3");
+        assert_eq!(lines[1], "5 3");
+        assert_eq!(lines[2], "255");
+    }
+
+    #[test]
+    fn to_ppm_pixel_data() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Tuple::new_color(1.5, 0.0, 0.0));
+        c.write_pixel(2, 1, Tuple::new_color(0.0, 0.5, 0.0));
+        c.write_pixel(4, 2, Tuple::new_color(-0.5, 0.0, 1.0));
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().collect();
+
+        assert_eq!(lines[3], "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0");
+        assert_eq!(lines[4], "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0");
+        assert_eq!(lines[5], "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255");
+    }
+
+    #[test]
+    fn to_ppm_wraps_long_lines() {
+        let mut c = Canvas::new(10, 2);
+        let color = Tuple::new_color(1.0, 0.8, 0.6);
+
+        for y in 0..2 {
+            for x in 0..10 {
+                c.write_pixel(x, y, color.clone());
+            }
+        }
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().collect();
+
+        assert_eq!(lines[3], "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204");
+        assert_eq!(lines[4], "153 255 204 153 255 204 153 255 204 153 255 204 153");
+        assert_eq!(lines[5], "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204");
+        assert_eq!(lines[6], "153 255 204 153 255 204 153 255 204 153 255 204 153");
+    }
+
+    #[test]
+    fn to_ppm_ends_with_newline() {
+        let c = Canvas::new(5, 3);
+        assert!(c.to_ppm().ends_with('\n'));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod canvas;
+pub mod light;
+pub mod material;
+pub mod matrix;
+pub mod primitives;
+pub mod ray;
+pub mod sphere;
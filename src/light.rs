@@ -0,0 +1,41 @@
+use crate::primitives::Tuple;
+
+/// A point light source: a single point with no size, shining with a given
+/// intensity in every direction
+pub struct PointLight {
+    /// Where the light is positioned
+    pub position: Tuple,
+    /// The color and brightness of the light
+    pub intensity: Tuple,
+}
+
+impl PointLight {
+    /// Returns a point light at `position` shining with `intensity`
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - A point where the light is positioned
+    /// * `intensity` - A color representing the light's color and brightness
+    pub fn new(position: Tuple, intensity: Tuple) -> PointLight {
+        PointLight {
+            position,
+            intensity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::light::PointLight;
+    use crate::primitives::Tuple;
+
+    #[test]
+    fn new() {
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+        let intensity = Tuple::new_color(1.0, 1.0, 1.0);
+        let light = PointLight::new(position.clone(), intensity.clone());
+
+        assert!(position.is_equal(&light.position));
+        assert!(intensity.is_equal(&light.intensity));
+    }
+}
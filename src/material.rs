@@ -0,0 +1,169 @@
+use crate::light::PointLight;
+use crate::primitives::Tuple;
+
+/// A Phong-shaded surface material
+pub struct Material {
+    /// The surface color
+    pub color: Tuple,
+    /// How much the surface is lit by ambient, non-directional light
+    pub ambient: f64,
+    /// How much the surface reflects light scattered in every direction
+    pub diffuse: f64,
+    /// How much the surface reflects light directly toward the eye
+    pub specular: f64,
+    /// How tightly the specular highlight is focused
+    pub shininess: f64,
+}
+
+impl Material {
+    /// Returns a default white material
+    pub fn new() -> Material {
+        Material {
+            color: Tuple::new_color(1.0, 1.0, 1.0),
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.0,
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Material {
+        Material::new()
+    }
+}
+
+/// Returns the color of a point on a lit surface, following the Phong
+/// reflection model
+///
+/// # Arguments
+///
+/// * `material` - The material of the surface being lit
+/// * `light` - The light illuminating the surface
+/// * `point` - The point on the surface being lit
+/// * `eye_vec` - The direction toward the eye
+/// * `normal_vec` - The surface normal at `point`
+pub fn lighting(
+    material: &Material,
+    light: &PointLight,
+    point: &Tuple,
+    eye_vec: &Tuple,
+    normal_vec: &Tuple,
+) -> Tuple {
+    let effective_color = material
+        .color
+        .hadamard(&light.intensity)
+        .expect("material color and light intensity must both be colors");
+    let light_vec = (light.position.clone() - point.clone()).normalize();
+    let ambient = effective_color.clone() * material.ambient;
+    let light_dot_normal = light_vec.dot(normal_vec);
+
+    let black = Tuple::new_color(0.0, 0.0, 0.0);
+    let (diffuse, specular) = if light_dot_normal < 0.0 {
+        (black.clone(), black)
+    } else {
+        let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+        let reflect_vec = light_vec.neg().reflect(normal_vec);
+        let reflect_dot_eye = reflect_vec.dot(eye_vec);
+
+        let specular = if reflect_dot_eye <= 0.0 {
+            black
+        } else {
+            let factor = reflect_dot_eye.powf(material.shininess);
+            light.intensity.clone() * material.specular * factor
+        };
+
+        (diffuse, specular)
+    };
+
+    Tuple::new_color(
+        ambient.red() + diffuse.red() + specular.red(),
+        ambient.green() + diffuse.green() + specular.green(),
+        ambient.blue() + diffuse.blue() + specular.blue(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::light::PointLight;
+    use crate::material::{lighting, Material};
+    use crate::primitives::Tuple;
+
+    #[test]
+    fn default_material() {
+        let m = Material::new();
+
+        assert!(Tuple::new_color(1.0, 1.0, 1.0).is_equal(&m.color));
+        assert_eq!(m.ambient, 0.1);
+        assert_eq!(m.diffuse, 0.9);
+        assert_eq!(m.specular, 0.9);
+        assert_eq!(m.shininess, 200.0);
+    }
+
+    #[test]
+    fn lighting_with_eye_between_light_and_surface() {
+        let m = Material::new();
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+
+        let eye_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let normal_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::new_point(0.0, 0.0, -10.0), Tuple::new_color(1.0, 1.0, 1.0));
+
+        let result = lighting(&m, &light, &position, &eye_vec, &normal_vec);
+        assert!(Tuple::new_color(1.9, 1.9, 1.9).is_equal(&result));
+    }
+
+    #[test]
+    fn lighting_with_eye_offset_45_degrees() {
+        let m = Material::new();
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+
+        let eye_vec = Tuple::new_vector(0.0, 2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt() / 2.0));
+        let normal_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::new_point(0.0, 0.0, -10.0), Tuple::new_color(1.0, 1.0, 1.0));
+
+        let result = lighting(&m, &light, &position, &eye_vec, &normal_vec);
+        assert!(Tuple::new_color(1.0, 1.0, 1.0).is_equal(&result));
+    }
+
+    #[test]
+    fn lighting_with_light_offset_45_degrees() {
+        let m = Material::new();
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+
+        let eye_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let normal_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::new_point(0.0, 10.0, -10.0), Tuple::new_color(1.0, 1.0, 1.0));
+
+        let result = lighting(&m, &light, &position, &eye_vec, &normal_vec);
+        assert!(Tuple::new_color(0.7364, 0.7364, 0.7364).is_equal(&result));
+    }
+
+    #[test]
+    fn lighting_with_eye_in_path_of_reflection_vector() {
+        let m = Material::new();
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+
+        let eye_vec = Tuple::new_vector(0.0, -(2.0_f64.sqrt() / 2.0), -(2.0_f64.sqrt() / 2.0));
+        let normal_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::new_point(0.0, 10.0, -10.0), Tuple::new_color(1.0, 1.0, 1.0));
+
+        let result = lighting(&m, &light, &position, &eye_vec, &normal_vec);
+        assert!(Tuple::new_color(1.6364, 1.6364, 1.6364).is_equal(&result));
+    }
+
+    #[test]
+    fn lighting_with_light_behind_surface() {
+        let m = Material::new();
+        let position = Tuple::new_point(0.0, 0.0, 0.0);
+
+        let eye_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let normal_vec = Tuple::new_vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::new_point(0.0, 0.0, 10.0), Tuple::new_color(1.0, 1.0, 1.0));
+
+        let result = lighting(&m, &light, &position, &eye_vec, &normal_vec);
+        assert!(Tuple::new_color(0.1, 0.1, 0.1).is_equal(&result));
+    }
+}
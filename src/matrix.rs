@@ -0,0 +1,594 @@
+use std::ops;
+
+use crate::primitives::{is_equal, Tuple, TupleKind};
+
+/// A 4x4 matrix used to represent and compose geometric transforms
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    elements: [[f64; 4]; 4],
+}
+
+impl Matrix {
+    /// Returns a matrix built from the given rows
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The 4x4 grid of float64 elements, row by row
+    pub fn new(elements: [[f64; 4]; 4]) -> Matrix {
+        Matrix { elements }
+    }
+
+    /// Returns the 4x4 identity matrix
+    pub fn identity() -> Matrix {
+        Matrix::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns if both matrices are equal (it has some rounding at the fifth house)
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A matrix to compare with
+    pub fn is_equal(&self, other: &Matrix) -> bool {
+        self.elements.iter().zip(other.elements.iter()).all(|(line, other_line)| {
+            line.iter()
+                .zip(other_line.iter())
+                .all(|(value, other_value)| is_equal(value, other_value))
+        })
+    }
+
+    /// Returns the element at `(row, col)`
+    pub fn at(&self, row: usize, col: usize) -> f64 {
+        self.elements[row][col]
+    }
+
+    /// Returns a new matrix with rows and columns swapped
+    pub fn transpose(&self) -> Matrix {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, line) in self.elements.iter().enumerate() {
+            for (col, &value) in line.iter().enumerate() {
+                result[col][row] = value;
+            }
+        }
+
+        Matrix::new(result)
+    }
+
+    /// Returns the matrix obtained by removing the given row and column
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to remove
+    /// * `col` - The column to remove
+    pub fn submatrix(&self, row: usize, col: usize) -> Vec<Vec<f64>> {
+        submatrix_of(&grid_of(&self.elements), row, col)
+    }
+
+    /// Returns the minor of the element at `(row, col)`: the determinant of
+    /// the submatrix obtained by removing that row and column
+    pub fn minor(&self, row: usize, col: usize) -> f64 {
+        determinant_of(&self.submatrix(row, col))
+    }
+
+    /// Returns the cofactor of the element at `(row, col)`: the minor, with
+    /// its sign flipped when `row + col` is odd
+    pub fn cofactor(&self, row: usize, col: usize) -> f64 {
+        let minor = self.minor(row, col);
+
+        if (row + col) % 2 == 1 {
+            -minor
+        } else {
+            minor
+        }
+    }
+
+    /// Returns the determinant of the matrix, computed via cofactor
+    /// expansion along the first row
+    pub fn determinant(&self) -> f64 {
+        (0..4)
+            .map(|col| self.elements[0][col] * self.cofactor(0, col))
+            .sum()
+    }
+
+    /// Returns the inverse of the matrix using the adjugate method
+    ///
+    /// # Return
+    ///
+    /// * `Option<Matrix>` - The inverse matrix, or none if the matrix is
+    /// not invertible (its determinant is zero)
+    pub fn inverse(&self) -> Option<Matrix> {
+        let determinant = self.determinant();
+
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, line) in result.iter_mut().enumerate() {
+            for (col, slot) in line.iter_mut().enumerate() {
+                *slot = self.cofactor(col, row) / determinant;
+            }
+        }
+
+        Some(Matrix::new(result))
+    }
+
+    /// Returns a matrix that translates a point by `(x, y, z)`
+    pub fn translation(x: f64, y: f64, z: f64) -> Matrix {
+        Matrix::new([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that scales by `(x, y, z)`
+    pub fn scaling(x: f64, y: f64, z: f64) -> Matrix {
+        Matrix::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the x axis by `r` radians
+    pub fn rotation_x(r: f64) -> Matrix {
+        Matrix::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, r.cos(), -r.sin(), 0.0],
+            [0.0, r.sin(), r.cos(), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the y axis by `r` radians
+    pub fn rotation_y(r: f64) -> Matrix {
+        Matrix::new([
+            [r.cos(), 0.0, r.sin(), 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-r.sin(), 0.0, r.cos(), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the z axis by `r` radians
+    pub fn rotation_z(r: f64) -> Matrix {
+        Matrix::new([
+            [r.cos(), -r.sin(), 0.0, 0.0],
+            [r.sin(), r.cos(), 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that shears each component in proportion to the others
+    ///
+    /// # Arguments
+    ///
+    /// * `xy` - How much x moves in proportion to y
+    /// * `xz` - How much x moves in proportion to z
+    /// * `yx` - How much y moves in proportion to x
+    /// * `yz` - How much y moves in proportion to z
+    /// * `zx` - How much z moves in proportion to x
+    /// * `zy` - How much z moves in proportion to y
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Matrix {
+        Matrix::new([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+/// Multiplies two matrices
+impl ops::Mul<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: Matrix) -> Matrix {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, line) in result.iter_mut().enumerate() {
+            for (col, slot) in line.iter_mut().enumerate() {
+                *slot = (0..4).map(|i| self.elements[row][i] * other.elements[i][col]).sum();
+            }
+        }
+
+        Matrix::new(result)
+    }
+}
+
+/// Multiplies a matrix by a tuple, treating the tuple's three visible
+/// elements as a column vector and preserving its kind. Points contribute a
+/// homogeneous fourth component of 1 (so translation applies to them) while
+/// every other kind contributes its own `w`, matching the crate's
+/// kind-tracked (rather than w-tracked) point/vector discipline
+impl ops::Mul<Tuple> for Matrix {
+    type Output = Tuple;
+
+    fn mul(self, other: Tuple) -> Tuple {
+        let w = if other.kind() == TupleKind::Point {
+            1.0
+        } else {
+            other.w()
+        };
+        let input = [other.x(), other.y(), other.z(), w];
+        let mut result = [other.x(), other.y(), other.z(), other.w()];
+
+        for (row, slot) in result.iter_mut().enumerate().take(3) {
+            *slot = (0..4).map(|i| self.elements[row][i] * input[i]).sum();
+        }
+
+        other.with_elements(result)
+    }
+}
+
+/// Converts a fixed 4x4 grid into a `Vec<Vec<f64>>` so it can be reduced by
+/// `submatrix_of`/`determinant_of` down to a 2x2 base case
+fn grid_of(elements: &[[f64; 4]; 4]) -> Vec<Vec<f64>> {
+    elements.iter().map(|row| row.to_vec()).collect()
+}
+
+/// Returns the grid obtained by removing the given row and column
+fn submatrix_of(grid: &[Vec<f64>], row: usize, col: usize) -> Vec<Vec<f64>> {
+    grid.iter()
+        .enumerate()
+        .filter(|(r, _)| *r != row)
+        .map(|(_, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|(c, _)| *c != col)
+                .map(|(_, value)| *value)
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the cofactor of the element at `(row, col)` of a square grid of
+/// size 3x3 or larger
+fn cofactor_of(grid: &[Vec<f64>], row: usize, col: usize) -> f64 {
+    let minor = determinant_of(&submatrix_of(grid, row, col));
+
+    if (row + col) % 2 == 1 {
+        -minor
+    } else {
+        minor
+    }
+}
+
+/// Returns the determinant of a square grid of size 2x2 or larger, via
+/// cofactor expansion along the first row, down to the 2x2 base case
+fn determinant_of(grid: &[Vec<f64>]) -> f64 {
+    let size = grid.len();
+
+    if size == 2 {
+        return grid[0][0] * grid[1][1] - grid[0][1] * grid[1][0];
+    }
+
+    (0..size).map(|col| grid[0][col] * cofactor_of(grid, 0, col)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::primitives::Tuple;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn new_and_at() {
+        let m = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.5, 6.5, 7.5, 8.5],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.5, 14.5, 15.5, 16.5],
+        ]);
+
+        assert_eq!(m.at(0, 0), 1.0);
+        assert_eq!(m.at(0, 3), 4.0);
+        assert_eq!(m.at(1, 0), 5.5);
+        assert_eq!(m.at(1, 2), 7.5);
+        assert_eq!(m.at(2, 2), 11.0);
+        assert_eq!(m.at(3, 0), 13.5);
+        assert_eq!(m.at(3, 2), 15.5);
+    }
+
+    #[test]
+    fn is_equal() {
+        let a = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ]);
+        let b = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ]);
+        let c = Matrix::new([
+            [2.0, 3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0, 9.0],
+            [8.0, 7.0, 6.0, 5.0],
+            [4.0, 3.0, 2.0, 1.0],
+        ]);
+
+        assert!(a.is_equal(&b));
+        assert!(!a.is_equal(&c));
+    }
+
+    #[test]
+    fn mul_matrix() {
+        let a = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ]);
+        let b = Matrix::new([
+            [-2.0, 1.0, 2.0, 3.0],
+            [3.0, 2.0, 1.0, -1.0],
+            [4.0, 3.0, 6.0, 5.0],
+            [1.0, 2.0, 7.0, 8.0],
+        ]);
+        let r = Matrix::new([
+            [20.0, 22.0, 50.0, 48.0],
+            [44.0, 54.0, 114.0, 108.0],
+            [40.0, 58.0, 110.0, 102.0],
+            [16.0, 26.0, 46.0, 42.0],
+        ]);
+
+        assert!(r.is_equal(&(a * b)));
+    }
+
+    #[test]
+    fn mul_tuple() {
+        let m = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 4.0, 2.0],
+            [8.0, 6.0, 4.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let t = Tuple::new(1.0, 2.0, 3.0, 1.0);
+        let r = Tuple::new(18.0, 24.0, 33.0, 1.0);
+
+        assert!(r.is_equal(&(m * t)));
+    }
+
+    #[test]
+    fn mul_identity() {
+        let a = Matrix::new([
+            [0.0, 1.0, 2.0, 4.0],
+            [1.0, 2.0, 4.0, 8.0],
+            [2.0, 4.0, 8.0, 16.0],
+            [4.0, 8.0, 16.0, 32.0],
+        ]);
+
+        assert!(a.is_equal(&(a.clone() * Matrix::identity())));
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Matrix::new([
+            [0.0, 9.0, 3.0, 0.0],
+            [9.0, 8.0, 0.0, 8.0],
+            [1.0, 8.0, 5.0, 3.0],
+            [0.0, 0.0, 5.0, 8.0],
+        ]);
+        let r = Matrix::new([
+            [0.0, 9.0, 1.0, 0.0],
+            [9.0, 8.0, 8.0, 0.0],
+            [3.0, 0.0, 5.0, 5.0],
+            [0.0, 8.0, 3.0, 8.0],
+        ]);
+
+        assert!(r.is_equal(&a.transpose()));
+        assert!(Matrix::identity().is_equal(&Matrix::identity().transpose()));
+    }
+
+    #[test]
+    fn submatrix() {
+        let a = Matrix::new([
+            [-6.0, 1.0, 1.0, 6.0],
+            [-8.0, 5.0, 8.0, 6.0],
+            [-1.0, 0.0, 8.0, 2.0],
+            [-7.0, 1.0, -1.0, 1.0],
+        ]);
+        let r = vec![
+            vec![-6.0, 1.0, 6.0],
+            vec![-8.0, 8.0, 6.0],
+            vec![-7.0, -1.0, 1.0],
+        ];
+
+        assert_eq!(a.submatrix(2, 1), r);
+    }
+
+    #[test]
+    fn minor_and_cofactor() {
+        let a = Matrix::new([
+            [3.0, 5.0, 0.0, 0.0],
+            [2.0, -1.0, -7.0, 0.0],
+            [6.0, -1.0, 5.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        assert_eq!(a.minor(1, 0), 25.0);
+        assert_eq!(a.cofactor(0, 0), -12.0);
+        assert_eq!(a.cofactor(1, 0), -25.0);
+    }
+
+    #[test]
+    fn determinant() {
+        let a = Matrix::new([
+            [-2.0, -8.0, 3.0, 5.0],
+            [-3.0, 1.0, 7.0, 3.0],
+            [1.0, 2.0, -9.0, 6.0],
+            [-6.0, 7.0, 7.0, -9.0],
+        ]);
+
+        assert_eq!(a.determinant(), -4071.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let a = Matrix::new([
+            [-5.0, 2.0, 6.0, -8.0],
+            [1.0, -5.0, 1.0, 8.0],
+            [7.0, 7.0, -6.0, -7.0],
+            [1.0, -3.0, 7.0, 4.0],
+        ]);
+        let r = Matrix::new([
+            [0.21805, 0.45113, 0.24060, -0.04511],
+            [-0.80827, -1.45677, -0.44361, 0.52068],
+            [-0.07895, -0.22368, -0.05263, 0.19737],
+            [-0.52256, -0.81391, -0.30075, 0.30639],
+        ]);
+
+        assert!(r.is_equal(&a.inverse().unwrap()));
+    }
+
+    #[test]
+    fn inverse_of_non_invertible_is_none() {
+        let a = Matrix::new([
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+
+        assert!(a.inverse().is_none());
+    }
+
+    #[test]
+    fn mul_by_inverse_recovers_original() {
+        let a = Matrix::new([
+            [3.0, -9.0, 7.0, 3.0],
+            [3.0, -8.0, 2.0, -9.0],
+            [-4.0, 4.0, 4.0, 1.0],
+            [-6.0, 5.0, -1.0, 1.0],
+        ]);
+        let b = Matrix::new([
+            [8.0, 2.0, 2.0, 2.0],
+            [3.0, -1.0, 7.0, 0.0],
+            [7.0, 0.0, 5.0, 4.0],
+            [6.0, -2.0, 0.0, 5.0],
+        ]);
+        let c = a.clone() * b.clone();
+
+        assert!(a.is_equal(&(c * b.inverse().unwrap())));
+    }
+
+    #[test]
+    fn translation() {
+        let transform = Matrix::translation(5.0, -3.0, 2.0);
+        let p = Tuple::new_point(-3.0, 4.0, 5.0);
+        let r = Tuple::new_point(2.0, 1.0, 7.0);
+        assert!(r.is_equal(&(transform * p)));
+
+        let transform = Matrix::translation(5.0, -3.0, 2.0);
+        let inv = transform.inverse().unwrap();
+        let p = Tuple::new_point(-3.0, 4.0, 5.0);
+        let r = Tuple::new_point(-8.0, 7.0, 3.0);
+        assert!(r.is_equal(&(inv * p)));
+
+        let transform = Matrix::translation(5.0, -3.0, 2.0);
+        let v = Tuple::new_vector(-3.0, 4.0, 5.0);
+        assert!(v.is_equal(&(transform * v.clone())));
+    }
+
+    #[test]
+    fn scaling() {
+        let transform = Matrix::scaling(2.0, 3.0, 4.0);
+        let p = Tuple::new_point(-4.0, 6.0, 8.0);
+        let r = Tuple::new_point(-8.0, 18.0, 32.0);
+        assert!(r.is_equal(&(transform * p)));
+
+        let transform = Matrix::scaling(2.0, 3.0, 4.0);
+        let v = Tuple::new_vector(-4.0, 6.0, 8.0);
+        let r = Tuple::new_vector(-8.0, 18.0, 32.0);
+        assert!(r.is_equal(&(transform * v)));
+
+        let transform = Matrix::scaling(2.0, 3.0, 4.0);
+        let inv = transform.inverse().unwrap();
+        let v = Tuple::new_vector(-4.0, 6.0, 8.0);
+        let r = Tuple::new_vector(-2.0, 2.0, 2.0);
+        assert!(r.is_equal(&(inv * v)));
+    }
+
+    #[test]
+    fn rotation_x() {
+        let p = Tuple::new_point(0.0, 1.0, 0.0);
+        let half_quarter = Matrix::rotation_x(PI / 4.0);
+        let full_quarter = Matrix::rotation_x(PI / 2.0);
+        let r1 = Tuple::new_point(0.0, 2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0);
+        let r2 = Tuple::new_point(0.0, 0.0, 1.0);
+
+        assert!(r1.is_equal(&(half_quarter * p.clone())));
+        assert!(r2.is_equal(&(full_quarter * p)));
+    }
+
+    #[test]
+    fn rotation_y() {
+        let p = Tuple::new_point(0.0, 0.0, 1.0);
+        let half_quarter = Matrix::rotation_y(PI / 4.0);
+        let full_quarter = Matrix::rotation_y(PI / 2.0);
+        let r1 = Tuple::new_point(2.0_f64.sqrt() / 2.0, 0.0, 2.0_f64.sqrt() / 2.0);
+        let r2 = Tuple::new_point(1.0, 0.0, 0.0);
+
+        assert!(r1.is_equal(&(half_quarter * p.clone())));
+        assert!(r2.is_equal(&(full_quarter * p)));
+    }
+
+    #[test]
+    fn rotation_z() {
+        let p = Tuple::new_point(0.0, 1.0, 0.0);
+        let half_quarter = Matrix::rotation_z(PI / 4.0);
+        let full_quarter = Matrix::rotation_z(PI / 2.0);
+        let r1 = Tuple::new_point(-(2.0_f64.sqrt() / 2.0), 2.0_f64.sqrt() / 2.0, 0.0);
+        let r2 = Tuple::new_point(-1.0, 0.0, 0.0);
+
+        assert!(r1.is_equal(&(half_quarter * p.clone())));
+        assert!(r2.is_equal(&(full_quarter * p)));
+    }
+
+    #[test]
+    fn shearing() {
+        let transform = Matrix::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let p = Tuple::new_point(2.0, 3.0, 4.0);
+        let r = Tuple::new_point(5.0, 3.0, 4.0);
+        assert!(r.is_equal(&(transform * p)));
+
+        let transform = Matrix::shearing(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        let p = Tuple::new_point(2.0, 3.0, 4.0);
+        let r = Tuple::new_point(2.0, 3.0, 7.0);
+        assert!(r.is_equal(&(transform * p)));
+    }
+
+    #[test]
+    fn chained_transforms() {
+        let p = Tuple::new_point(1.0, 0.0, 1.0);
+        let a = Matrix::rotation_x(PI / 2.0);
+        let b = Matrix::scaling(5.0, 5.0, 5.0);
+        let c = Matrix::translation(10.0, 5.0, 7.0);
+
+        let p2 = a * p;
+        let r2 = Tuple::new_point(1.0, -1.0, 0.0);
+        assert!(r2.is_equal(&p2));
+
+        let p3 = b * p2;
+        let r3 = Tuple::new_point(5.0, -5.0, 0.0);
+        assert!(r3.is_equal(&p3));
+
+        let p4 = c * p3;
+        let r4 = Tuple::new_point(15.0, 0.0, 7.0);
+        assert!(r4.is_equal(&p4));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops;
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum TupleKind {
     None,
@@ -7,7 +9,7 @@ pub enum TupleKind {
 }
 
 /// A very basic element representing points, vectors and colors
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Tuple {
     /// A Tuple can be a point, a vector, a color or none of them
     kind: TupleKind,
@@ -38,7 +40,7 @@ impl Tuple {
     /// * `e0` - A float64 representing the first element
     /// * `e1` - A float64 representing the second element
     /// * `e2` - A float64 representing the third element
-    fn new_point(e0: f64, e1: f64, e2: f64) -> Tuple {
+    pub fn new_point(e0: f64, e1: f64, e2: f64) -> Tuple {
         Tuple {
             kind: TupleKind::Point,
             elements: [e0, e1, e2, 0.0],
@@ -52,7 +54,7 @@ impl Tuple {
     /// * `e0` - A float64 representing the first element
     /// * `e1` - A float64 representing the second element
     /// * `e2` - A float64 representing the third element
-    fn new_vector(e0: f64, e1: f64, e2: f64) -> Tuple {
+    pub fn new_vector(e0: f64, e1: f64, e2: f64) -> Tuple {
         Tuple {
             kind: TupleKind::Vector,
             elements: [e0, e1, e2, 0.0],
@@ -66,7 +68,7 @@ impl Tuple {
     /// * `e0` - A float64 representing the first element
     /// * `e1` - A float64 representing the second element
     /// * `e2` - A float64 representing the third element
-    fn new_color(e0: f64, e1: f64, e2: f64) -> Tuple {
+    pub fn new_color(e0: f64, e1: f64, e2: f64) -> Tuple {
         Tuple {
             kind: TupleKind::Color,
             elements: [e0, e1, e2, 0.0],
@@ -168,9 +170,243 @@ impl Tuple {
             ]
         }
     }
+
+    /// Returns the magnitude (length) of self
+    ///
+    /// # Return
+    ///
+    /// * `f64` - The square root of the sum of the squares of the elements
+    pub fn magnitude(&self) -> f64 {
+        (self.elements[0].powi(2)
+            + self.elements[1].powi(2)
+            + self.elements[2].powi(2)
+            + self.elements[3].powi(2))
+        .sqrt()
+    }
+
+    /// Returns a new tuple with the same kind and direction as self, scaled
+    /// to a magnitude of 1
+    ///
+    /// # Return
+    ///
+    /// * `Tuple` - The normalized tuple, or self unchanged when the
+    /// magnitude is zero
+    pub fn normalize(&self) -> Tuple {
+        let magnitude = self.magnitude();
+
+        if magnitude == 0.0 {
+            return Tuple {
+                kind: self.kind.clone(),
+                elements: self.elements,
+            };
+        }
+
+        Tuple {
+            kind: self.kind.clone(),
+            elements: [
+                self.elements[0] / magnitude,
+                self.elements[1] / magnitude,
+                self.elements[2] / magnitude,
+                self.elements[3] / magnitude,
+            ],
+        }
+    }
+
+    /// Returns the dot product between self and other
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A tuple to compute the dot product with
+    ///
+    /// # Return
+    ///
+    /// * `f64` - The component-wise sum of products
+    pub fn dot(&self, other: &Tuple) -> f64 {
+        self.elements[0] * other.elements[0]
+            + self.elements[1] * other.elements[1]
+            + self.elements[2] * other.elements[2]
+            + self.elements[3] * other.elements[3]
+    }
+
+    /// Returns the cross product between self and other
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A vector to compute the cross product with
+    ///
+    /// # Return
+    ///
+    /// * `Option<Tuple>` - The cross product vector, or none if self or
+    /// other is not a vector
+    pub fn cross(&self, other: &Tuple) -> Option<Tuple> {
+        if self.kind != TupleKind::Vector || other.kind != TupleKind::Vector {
+            return None;
+        }
+
+        Some(Tuple::new_vector(
+            self.elements[1] * other.elements[2] - self.elements[2] * other.elements[1],
+            self.elements[2] * other.elements[0] - self.elements[0] * other.elements[2],
+            self.elements[0] * other.elements[1] - self.elements[1] * other.elements[0],
+        ))
+    }
+
+    /// Returns the kind of the tuple (point, vector, color or none)
+    pub fn kind(&self) -> TupleKind {
+        self.kind.clone()
+    }
+
+    /// Returns the first element of the tuple
+    pub fn x(&self) -> f64 {
+        self.elements[0]
+    }
+
+    /// Returns the second element of the tuple
+    pub fn y(&self) -> f64 {
+        self.elements[1]
+    }
+
+    /// Returns the third element of the tuple
+    pub fn z(&self) -> f64 {
+        self.elements[2]
+    }
+
+    /// Returns the fourth element of the tuple
+    pub fn w(&self) -> f64 {
+        self.elements[3]
+    }
+
+    /// Returns a new tuple with the same kind as self but with the given
+    /// elements, used by matrix transforms to preserve the kind of a
+    /// point or vector through multiplication
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The four float64 elements of the resulting tuple
+    pub fn with_elements(&self, elements: [f64; 4]) -> Tuple {
+        Tuple {
+            kind: self.kind.clone(),
+            elements,
+        }
+    }
+
+    /// Returns the red channel of a color tuple
+    pub fn red(&self) -> f64 {
+        self.elements[0]
+    }
+
+    /// Returns the green channel of a color tuple
+    pub fn green(&self) -> f64 {
+        self.elements[1]
+    }
+
+    /// Returns the blue channel of a color tuple
+    pub fn blue(&self) -> f64 {
+        self.elements[2]
+    }
+
+    /// Returns the Hadamard (element-wise) product between self and other,
+    /// used to blend a surface color with a light color
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A color to multiply with
+    ///
+    /// # Return
+    ///
+    /// * `Option<Tuple>` - The blended color, or none if self or other is
+    /// not a `Color`
+    pub fn hadamard(&self, other: &Tuple) -> Option<Tuple> {
+        if self.kind != TupleKind::Color || other.kind != TupleKind::Color {
+            return None;
+        }
+
+        Some(Tuple::new_color(
+            self.elements[0] * other.elements[0],
+            self.elements[1] * other.elements[1],
+            self.elements[2] * other.elements[2],
+        ))
+    }
+
+    /// Returns self reflected around the given normal
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The normal vector to reflect around
+    pub fn reflect(&self, normal: &Tuple) -> Tuple {
+        self.clone() - normal.clone() * (2.0 * self.dot(normal))
+    }
 }
 
-fn is_equal(a: &f64, b: &f64) -> bool {
+/// Adds two tuples following the same kind-combination rules as `Tuple::add`
+///
+/// # Panics
+///
+/// Panics when both operands are points, since a point cannot be added to a point
+impl ops::Add for Tuple {
+    type Output = Tuple;
+
+    fn add(self, other: Tuple) -> Tuple {
+        Tuple::add(&self, &other).expect("cannot add a point to a point")
+    }
+}
+
+/// Subtracts two tuples following the same kind-combination rules as `Tuple::sub`
+///
+/// # Panics
+///
+/// Panics when subtracting a point from a vector
+impl ops::Sub for Tuple {
+    type Output = Tuple;
+
+    fn sub(self, other: Tuple) -> Tuple {
+        Tuple::sub(&self, &other).expect("cannot subtract a point from a vector")
+    }
+}
+
+/// Returns the opposite of a tuple, preserving its kind
+impl ops::Neg for Tuple {
+    type Output = Tuple;
+
+    fn neg(self) -> Tuple {
+        Tuple::neg(&self)
+    }
+}
+
+/// Scales a tuple by a scalar, preserving its kind
+impl ops::Mul<f64> for Tuple {
+    type Output = Tuple;
+
+    fn mul(self, scalar: f64) -> Tuple {
+        Tuple {
+            kind: self.kind,
+            elements: [
+                self.elements[0] * scalar,
+                self.elements[1] * scalar,
+                self.elements[2] * scalar,
+                self.elements[3] * scalar,
+            ],
+        }
+    }
+}
+
+/// Divides a tuple by a scalar, preserving its kind
+impl ops::Div<f64> for Tuple {
+    type Output = Tuple;
+
+    fn div(self, scalar: f64) -> Tuple {
+        Tuple {
+            kind: self.kind,
+            elements: [
+                self.elements[0] / scalar,
+                self.elements[1] / scalar,
+                self.elements[2] / scalar,
+                self.elements[3] / scalar,
+            ],
+        }
+    }
+}
+
+pub(crate) fn is_equal(a: &f64, b: &f64) -> bool {
     const EPSILON: f64 = 0.00001;
 
     (a - b).abs() < EPSILON
@@ -321,4 +557,151 @@ mod tests {
         let a = &t1.neg();
         assert!(&r.is_equal(&a));
     }
+
+    #[test]
+    fn magnitude() {
+        let v = Tuple::new_vector(1.0, 0.0, 0.0);
+        assert_eq!(v.magnitude(), 1.0);
+
+        let v = Tuple::new_vector(0.0, 1.0, 0.0);
+        assert_eq!(v.magnitude(), 1.0);
+
+        let v = Tuple::new_vector(0.0, 0.0, 1.0);
+        assert_eq!(v.magnitude(), 1.0);
+
+        let v = Tuple::new_vector(1.0, 2.0, 3.0);
+        assert_eq!(v.magnitude(), 14.0_f64.sqrt());
+
+        let v = Tuple::new_vector(-1.0, -2.0, -3.0);
+        assert_eq!(v.magnitude(), 14.0_f64.sqrt());
+    }
+
+    #[test]
+    fn normalize() {
+        let v = Tuple::new_vector(4.0, 0.0, 0.0);
+        let r = Tuple::new_vector(1.0, 0.0, 0.0);
+        assert!(&r.is_equal(&v.normalize()));
+
+        let v = Tuple::new_vector(1.0, 2.0, 3.0);
+        let r = Tuple::new_vector(
+            1.0 / 14.0_f64.sqrt(),
+            2.0 / 14.0_f64.sqrt(),
+            3.0 / 14.0_f64.sqrt(),
+        );
+        assert!(&r.is_equal(&v.normalize()));
+
+        let n = v.normalize();
+        assert_eq!(n.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn dot() {
+        let t1 = Tuple::new_vector(1.0, 2.0, 3.0);
+        let t2 = Tuple::new_vector(2.0, 3.0, 4.0);
+        assert_eq!(t1.dot(&t2), 20.0);
+    }
+
+    #[test]
+    fn cross() {
+        let t1 = Tuple::new_vector(1.0, 2.0, 3.0);
+        let t2 = Tuple::new_vector(2.0, 3.0, 4.0);
+
+        let r = Tuple::new_vector(-1.0, 2.0, -1.0);
+        assert!(&r.is_equal(t1.cross(&t2).as_ref().unwrap()));
+
+        let r = Tuple::new_vector(1.0, -2.0, 1.0);
+        assert!(&r.is_equal(t2.cross(&t1).as_ref().unwrap()));
+
+        // cross between a point and a vector should return none
+        let p1 = Tuple::new_point(1.0, 2.0, 3.0);
+        assert!(p1.cross(&t2).is_none());
+    }
+
+    #[test]
+    fn op_add() {
+        let t1 = Tuple::new_point(3.0, -2.0, 5.0);
+        let t2 = Tuple::new_vector(-2.0, 3.0, 1.0);
+        let r = Tuple::new_point(1.0, 1.0, 6.0);
+        assert!(&r.is_equal(&(t1 + t2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a point to a point")]
+    fn op_add_point_to_point_panics() {
+        let t1 = Tuple::new_point(3.0, -2.0, 5.0);
+        let t2 = Tuple::new_point(-2.0, 3.0, 1.0);
+        let _ = t1 + t2;
+    }
+
+    #[test]
+    fn op_sub() {
+        let t1 = Tuple::new_point(3.0, 2.0, 1.0);
+        let t2 = Tuple::new_point(5.0, 6.0, 7.0);
+        let r = Tuple::new_vector(-2.0, -4.0, -6.0);
+        assert!(&r.is_equal(&(t1 - t2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract a point from a vector")]
+    fn op_sub_point_from_vector_panics() {
+        let t1 = Tuple::new_vector(3.0, 2.0, 1.0);
+        let t2 = Tuple::new_point(5.0, 6.0, 7.0);
+        let _ = t1 - t2;
+    }
+
+    #[test]
+    fn op_neg() {
+        let t1 = Tuple::new(1.0, -2.0, 3.0, -4.0);
+        let r = Tuple::new(-1.0, 2.0, -3.0, 4.0);
+        assert!(&r.is_equal(&(-t1)));
+    }
+
+    #[test]
+    fn op_mul() {
+        let t1 = Tuple::new(1.0, -2.0, 3.0, -4.0);
+        let r = Tuple::new(3.5, -7.0, 10.5, -14.0);
+        assert!(&r.is_equal(&(t1 * 3.5)));
+
+        let t1 = Tuple::new(1.0, -2.0, 3.0, -4.0);
+        let r = Tuple::new(0.5, -1.0, 1.5, -2.0);
+        assert!(&r.is_equal(&(t1 * 0.5)));
+    }
+
+    #[test]
+    fn op_div() {
+        let t1 = Tuple::new(1.0, -2.0, 3.0, -4.0);
+        let r = Tuple::new(0.5, -1.0, 1.5, -2.0);
+        assert!(&r.is_equal(&(t1 / 2.0)));
+    }
+
+    #[test]
+    fn hadamard() {
+        let c1 = Tuple::new_color(1.0, 0.2, 0.4);
+        let c2 = Tuple::new_color(0.9, 1.0, 0.1);
+        let r = Tuple::new_color(0.9, 0.2, 0.04);
+        assert!(&r.is_equal(c1.hadamard(&c2).as_ref().unwrap()));
+    }
+
+    #[test]
+    fn hadamard_non_color_returns_none() {
+        let c1 = Tuple::new_color(1.0, 0.2, 0.4);
+        let v1 = Tuple::new_vector(0.9, 1.0, 0.1);
+        assert!(c1.hadamard(&v1).is_none());
+        assert!(v1.hadamard(&c1).is_none());
+    }
+
+    #[test]
+    fn reflect() {
+        // reflecting a vector approaching at 45 degrees
+        let v = Tuple::new_vector(1.0, -1.0, 0.0);
+        let n = Tuple::new_vector(0.0, 1.0, 0.0);
+        let r = Tuple::new_vector(1.0, 1.0, 0.0);
+        assert!(r.is_equal(&v.reflect(&n)));
+
+        // reflecting a vector off a slanted surface
+        let v = Tuple::new_vector(0.0, -1.0, 0.0);
+        let n = Tuple::new_vector(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0);
+        let r = Tuple::new_vector(1.0, 0.0, 0.0);
+        assert!(r.is_equal(&v.reflect(&n)));
+    }
 }
@@ -0,0 +1,90 @@
+use crate::matrix::Matrix;
+use crate::primitives::Tuple;
+
+/// A ray of light, with an origin point and a direction vector
+pub struct Ray {
+    /// The point the ray starts from
+    pub origin: Tuple,
+    /// The direction the ray travels in
+    pub direction: Tuple,
+}
+
+impl Ray {
+    /// Returns a ray with the given origin and direction
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - A point the ray starts from
+    /// * `direction` - A vector the ray travels in
+    pub fn new(origin: Tuple, direction: Tuple) -> Ray {
+        Ray { origin, direction }
+    }
+
+    /// Returns the point the ray has reached after travelling for `t`
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - How far along the ray to travel
+    pub fn position(&self, t: f64) -> Tuple {
+        self.origin.clone() + self.direction.clone() * t
+    }
+
+    /// Returns a new ray with origin and direction both transformed by `m`
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - The matrix to apply
+    pub fn transform(&self, m: &Matrix) -> Ray {
+        Ray::new(
+            m.clone() * self.origin.clone(),
+            m.clone() * self.direction.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::primitives::Tuple;
+    use crate::ray::Ray;
+
+    #[test]
+    fn new() {
+        let origin = Tuple::new_point(1.0, 2.0, 3.0);
+        let direction = Tuple::new_vector(4.0, 5.0, 6.0);
+        let r = Ray::new(origin.clone(), direction.clone());
+
+        assert!(origin.is_equal(&r.origin));
+        assert!(direction.is_equal(&r.direction));
+    }
+
+    #[test]
+    fn position() {
+        let r = Ray::new(Tuple::new_point(2.0, 3.0, 4.0), Tuple::new_vector(1.0, 0.0, 0.0));
+
+        assert!(Tuple::new_point(2.0, 3.0, 4.0).is_equal(&r.position(0.0)));
+        assert!(Tuple::new_point(3.0, 3.0, 4.0).is_equal(&r.position(1.0)));
+        assert!(Tuple::new_point(1.0, 3.0, 4.0).is_equal(&r.position(-1.0)));
+        assert!(Tuple::new_point(4.5, 3.0, 4.0).is_equal(&r.position(2.5)));
+    }
+
+    #[test]
+    fn transform_translation() {
+        let r = Ray::new(Tuple::new_point(1.0, 2.0, 3.0), Tuple::new_vector(0.0, 1.0, 0.0));
+        let m = Matrix::translation(3.0, 4.0, 5.0);
+        let r2 = r.transform(&m);
+
+        assert!(Tuple::new_point(4.0, 6.0, 8.0).is_equal(&r2.origin));
+        assert!(Tuple::new_vector(0.0, 1.0, 0.0).is_equal(&r2.direction));
+    }
+
+    #[test]
+    fn transform_scaling() {
+        let r = Ray::new(Tuple::new_point(1.0, 2.0, 3.0), Tuple::new_vector(0.0, 1.0, 0.0));
+        let m = Matrix::scaling(2.0, 3.0, 4.0);
+        let r2 = r.transform(&m);
+
+        assert!(Tuple::new_point(2.0, 6.0, 12.0).is_equal(&r2.origin));
+        assert!(Tuple::new_vector(0.0, 3.0, 0.0).is_equal(&r2.direction));
+    }
+}
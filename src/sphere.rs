@@ -0,0 +1,269 @@
+use crate::matrix::Matrix;
+use crate::primitives::Tuple;
+use crate::ray::Ray;
+
+/// A unit sphere centered at the origin, with its own transform matrix
+pub struct Sphere {
+    /// The transform applied to the sphere, from object space to world space
+    transform: Matrix,
+}
+
+impl Sphere {
+    /// Returns a unit sphere centered at the origin with an identity transform
+    pub fn new() -> Sphere {
+        Sphere {
+            transform: Matrix::identity(),
+        }
+    }
+
+    /// Returns the sphere's transform
+    pub fn transform(&self) -> &Matrix {
+        &self.transform
+    }
+
+    /// Sets the sphere's transform
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The new transform, from object space to world space
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.transform = transform;
+    }
+
+    /// Returns the `t` values where `ray` intersects the sphere, in
+    /// ascending order, or an empty vector when the ray misses
+    ///
+    /// # Arguments
+    ///
+    /// * `ray` - The ray to intersect, in world space
+    pub fn intersect(&self, ray: &Ray) -> Vec<f64> {
+        let inverse = match self.transform.inverse() {
+            Some(inverse) => inverse,
+            None => return vec![],
+        };
+        let local_ray = ray.transform(&inverse);
+
+        let sphere_to_ray = local_ray.origin.clone() - Tuple::new_point(0.0, 0.0, 0.0);
+        let a = local_ray.direction.dot(&local_ray.direction);
+        let b = 2.0 * local_ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+        let discriminant = b.powi(2) - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return vec![];
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        let t1 = (-b - sqrt_discriminant) / (2.0 * a);
+        let t2 = (-b + sqrt_discriminant) / (2.0 * a);
+
+        if t1 <= t2 {
+            vec![t1, t2]
+        } else {
+            vec![t2, t1]
+        }
+    }
+
+    /// Returns the surface normal at `world_point`, a point assumed to lie
+    /// on the sphere
+    ///
+    /// # Arguments
+    ///
+    /// * `world_point` - The point to compute the normal at, in world space
+    pub fn normal_at(&self, world_point: &Tuple) -> Tuple {
+        let inverse = self
+            .transform
+            .inverse()
+            .expect("sphere transform must be invertible");
+        let object_point = inverse.clone() * world_point.clone();
+        let object_normal = object_point - Tuple::new_point(0.0, 0.0, 0.0);
+        let world_normal = inverse.transpose() * object_normal;
+
+        world_normal.normalize()
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Sphere {
+        Sphere::new()
+    }
+}
+
+/// Returns the lowest non-negative `t` among `intersections`, the one that
+/// would actually be visible to the ray
+///
+/// # Arguments
+///
+/// * `intersections` - The `t` values to pick a hit from
+pub fn hit(intersections: &[f64]) -> Option<f64> {
+    intersections
+        .iter()
+        .copied()
+        .filter(|t| *t >= 0.0)
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::matrix::Matrix;
+    use crate::primitives::Tuple;
+    use crate::ray::Ray;
+    use crate::sphere::{hit, Sphere};
+
+    #[test]
+    fn intersect_two_points() {
+        let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs, vec![4.0, 6.0]);
+    }
+
+    #[test]
+    fn intersect_tangent() {
+        let r = Ray::new(Tuple::new_point(0.0, 1.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs, vec![5.0, 5.0]);
+    }
+
+    #[test]
+    fn intersect_misses() {
+        let r = Ray::new(Tuple::new_point(0.0, 2.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn intersect_originates_inside() {
+        let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs, vec![-1.0, 1.0]);
+    }
+
+    #[test]
+    fn intersect_sphere_behind_ray() {
+        let r = Ray::new(Tuple::new_point(0.0, 0.0, 5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs, vec![-6.0, -4.0]);
+    }
+
+    #[test]
+    fn default_transform_is_identity() {
+        let s = Sphere::new();
+        assert!(Matrix::identity().is_equal(s.transform()));
+    }
+
+    #[test]
+    fn set_transform() {
+        let mut s = Sphere::new();
+        let t = Matrix::translation(2.0, 3.0, 4.0);
+        s.set_transform(t.clone());
+
+        assert!(t.is_equal(s.transform()));
+    }
+
+    #[test]
+    fn intersect_scaled_sphere() {
+        let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Matrix::scaling(2.0, 2.0, 2.0));
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs, vec![3.0, 7.0]);
+    }
+
+    #[test]
+    fn intersect_translated_sphere() {
+        let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Matrix::translation(5.0, 0.0, 0.0));
+        let xs = s.intersect(&r);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn hit_all_positive() {
+        let xs = vec![1.0, 2.0];
+        assert_eq!(hit(&xs), Some(1.0));
+    }
+
+    #[test]
+    fn hit_some_negative() {
+        let xs = vec![-1.0, 1.0];
+        assert_eq!(hit(&xs), Some(1.0));
+    }
+
+    #[test]
+    fn hit_all_negative() {
+        let xs = vec![-2.0, -1.0];
+        assert_eq!(hit(&xs), None);
+    }
+
+    #[test]
+    fn hit_picks_lowest_nonnegative() {
+        let xs = vec![5.0, 7.0, -3.0, 2.0];
+        assert_eq!(hit(&xs), Some(2.0));
+    }
+
+    #[test]
+    fn normal_at_axis_points() {
+        let s = Sphere::new();
+
+        let n = s.normal_at(&Tuple::new_point(1.0, 0.0, 0.0));
+        assert!(Tuple::new_vector(1.0, 0.0, 0.0).is_equal(&n));
+
+        let n = s.normal_at(&Tuple::new_point(0.0, 1.0, 0.0));
+        assert!(Tuple::new_vector(0.0, 1.0, 0.0).is_equal(&n));
+
+        let n = s.normal_at(&Tuple::new_point(0.0, 0.0, 1.0));
+        assert!(Tuple::new_vector(0.0, 0.0, 1.0).is_equal(&n));
+    }
+
+    #[test]
+    fn normal_at_nonaxial_point() {
+        let s = Sphere::new();
+        let v = 3.0_f64.sqrt() / 3.0;
+        let n = s.normal_at(&Tuple::new_point(v, v, v));
+
+        assert!(Tuple::new_vector(v, v, v).is_equal(&n));
+    }
+
+    #[test]
+    fn normal_is_normalized() {
+        let s = Sphere::new();
+        let v = 3.0_f64.sqrt() / 3.0;
+        let n = s.normal_at(&Tuple::new_point(v, v, v));
+
+        assert!(n.is_equal(&n.normalize()));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn normal_on_translated_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Matrix::translation(0.0, 1.0, 0.0));
+        let n = s.normal_at(&Tuple::new_point(0.0, 1.70711, -0.70711));
+
+        assert!(Tuple::new_vector(0.0, 0.70711, -0.70711).is_equal(&n));
+    }
+
+    #[test]
+    fn normal_on_transformed_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Matrix::scaling(1.0, 0.5, 1.0) * Matrix::rotation_z(PI / 5.0));
+        let n = s.normal_at(&Tuple::new_point(0.0, 2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt() / 2.0)));
+
+        assert!(Tuple::new_vector(0.0, 0.97014, -0.24254).is_equal(&n));
+    }
+}